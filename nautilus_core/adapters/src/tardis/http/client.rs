@@ -13,9 +13,16 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::env;
+use std::{
+    collections::HashMap,
+    env,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
+use futures::stream::{self, StreamExt};
 use nautilus_common::version::USER_AGENT;
+use rand::Rng;
 
 use super::{
     types::{InstrumentInfo, Response},
@@ -29,12 +36,240 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     /// An error when sending a request to the server.
-    #[error("Error sending request: {0}")]
-    Request(#[from] reqwest::Error),
+    #[error("Error sending request to {url}: {source}")]
+    Request { url: String, source: reqwest::Error },
+
+    /// An error response returned by the server.
+    #[error("Error response from {url} ({status}): {body}")]
+    Api {
+        status: reqwest::StatusCode,
+        url: String,
+        body: String,
+        retry_after: Option<Duration>,
+    },
 
     /// An error when deserializing the response from the server.
-    #[error("Error deserializing message: {0}")]
-    Deserialization(#[from] serde_json::Error),
+    #[error("Error deserializing message from {url}: {source}")]
+    Deserialization { url: String, source: serde_json::Error },
+
+    /// The request failed after one or more retries, either because the retry attempts were
+    /// exhausted or because a retry returned a non-retriable error; wraps that last error.
+    #[error("Request to {url} failed after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        attempts: u32,
+        url: String,
+        source: Box<Error>,
+    },
+
+    /// An error constructing the underlying HTTP client.
+    #[error("Error building HTTP client: {0}")]
+    Build(#[from] reqwest::Error),
+}
+
+impl Error {
+    /// Returns `true` if the error represents a transient failure worth retrying (a connect,
+    /// timeout, or connection-reset error, or an HTTP 429/5xx response).
+    fn is_retriable(&self) -> bool {
+        match self {
+            Self::Request { source, .. } => {
+                source.is_timeout() || source.is_connect() || is_connection_reset(source)
+            }
+            Self::Api { status, .. } => status.as_u16() == 429 || status.is_server_error(),
+            Self::Deserialization { .. } | Self::RetriesExhausted { .. } | Self::Build(_) => false,
+        }
+    }
+}
+
+/// Returns `true` if `err`'s source chain contains an [`std::io::Error`] with
+/// [`std::io::ErrorKind::ConnectionReset`], which `reqwest::Error::is_connect` does not catch on
+/// its own (the reset can surface mid-stream, after the connection was already established).
+fn is_connection_reset(err: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            if io_err.kind() == std::io::ErrorKind::ConnectionReset {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Configuration for retrying transient `TardisHttpClient` request failures with exponential
+/// backoff.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// The maximum number of retry attempts before giving up.
+    pub max_retries: u32,
+    /// The initial backoff delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum backoff delay between retries.
+    pub max_backoff: Duration,
+    /// If true, apply full jitter (a random delay between zero and the computed backoff) to
+    /// avoid thundering-herd retries.
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+/// The maximum extra jitter added on top of a server-provided `Retry-After` delay.
+const RETRY_AFTER_JITTER: Duration = Duration::from_millis(250);
+
+impl RetryConfig {
+    /// Computes the backoff delay for the given zero-based `attempt`, honoring a `Retry-After`
+    /// header value when present.
+    ///
+    /// A `Retry-After` value is always honored in full: the server asked for a specific delay,
+    /// so it is neither clamped to `max_backoff` nor reduced by jitter, though a small amount of
+    /// positive jitter may be added on top to avoid a thundering herd of retries. Without a
+    /// `Retry-After`, the delay is computed via exponential backoff, clamped to `max_backoff`,
+    /// and full jitter applied if enabled.
+    fn backoff_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return if self.jitter {
+                let extra_millis =
+                    rand::thread_rng().gen_range(0..=RETRY_AFTER_JITTER.as_millis() as u64);
+                retry_after + Duration::from_millis(extra_millis)
+            } else {
+                retry_after
+            };
+        }
+
+        let exp = 2u32.saturating_pow(attempt);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(exp)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let max_millis = delay.as_millis().max(1) as u64;
+            Duration::from_millis(rand::thread_rng().gen_range(0..=max_millis))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parses a `Retry-After` header value, which is either a number of seconds or an HTTP-date.
+fn parse_retry_after(value: &reqwest::header::HeaderValue) -> Option<Duration> {
+    let value = value.to_str().ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let at = httpdate::parse_http_date(value).ok()?;
+    at.duration_since(std::time::SystemTime::now()).ok()
+}
+
+/// The parsed `Cache-Control` directives relevant to response caching.
+#[derive(Debug, Clone, Copy, Default)]
+struct CacheControlPolicy {
+    max_age: Option<Duration>,
+    no_store: bool,
+}
+
+impl CacheControlPolicy {
+    fn parse(value: &str) -> Self {
+        let mut policy = Self::default();
+
+        for directive in value.split(',') {
+            let directive = directive.trim();
+            if directive.eq_ignore_ascii_case("no-store") {
+                policy.no_store = true;
+            } else if let Some(seconds) = directive.to_ascii_lowercase().strip_prefix("max-age=") {
+                if let Ok(seconds) = seconds.parse::<u64>() {
+                    policy.max_age = Some(Duration::from_secs(seconds));
+                }
+            }
+        }
+
+        policy
+    }
+}
+
+/// A cached HTTP response body, together with the validators and freshness policy needed to
+/// revalidate it with a conditional request.
+#[derive(Debug, Clone)]
+pub struct CacheEntry {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    policy: CacheControlPolicy,
+    stored_at: Instant,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        self.policy
+            .max_age
+            .is_some_and(|max_age| self.stored_at.elapsed() < max_age)
+    }
+
+    fn has_validator(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+
+    /// Returns a copy of this entry with its freshness window reset to now, as happens when a
+    /// revalidation request returns `304 Not Modified`.
+    fn refreshed(&self) -> Self {
+        Self {
+            stored_at: Instant::now(),
+            ..self.clone()
+        }
+    }
+}
+
+/// A store of cached [`TardisHttpClient`] responses, keyed by request URL.
+///
+/// An in-memory [`InMemoryCache`] implementation is provided as the default; a disk-backed
+/// implementation can be supplied instead via [`TardisHttpClient::with_cache`].
+pub trait Cache: Send + Sync + std::fmt::Debug {
+    /// Returns the cached entry for `url`, if any.
+    fn get(&self, url: &str) -> Option<CacheEntry>;
+
+    /// Inserts or replaces the cached entry for `url`.
+    fn put(&self, url: &str, entry: CacheEntry);
+
+    /// Removes the cached entry for `url`, if any.
+    fn remove(&self, url: &str);
+
+    /// Removes all cached entries.
+    fn clear(&self);
+}
+
+/// An in-memory, `HashMap`-backed [`Cache`] implementation.
+#[derive(Debug, Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl Cache for InMemoryCache {
+    fn get(&self, url: &str) -> Option<CacheEntry> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(url.to_string(), entry);
+    }
+
+    fn remove(&self, url: &str) {
+        self.entries.lock().unwrap().remove(url);
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
 }
 
 /// A Tardis HTTP API client.
@@ -47,38 +282,200 @@ pub struct TardisHttpClient {
     base_url: String,
     api_key: String,
     client: reqwest::Client,
+    retry: RetryConfig,
+    cache: Option<Arc<dyn Cache>>,
 }
 
-impl TardisHttpClient {
-    /// Creates a new [`TardisHttpClient`] instance.
-    pub fn new(api_key: Option<&str>, base_url: Option<&str>) -> Self {
-        let api_key = api_key.map(ToString::to_string).unwrap_or_else(|| {
+/// Builder for configuring and constructing a [`TardisHttpClient`].
+///
+/// Unlike the `TardisHttpClient::new`/`with_*` constructors, [`TardisHttpClientBuilder::build`]
+/// returns a [`Result`] rather than panicking, since `reqwest::Client` construction can fail
+/// (e.g. an invalid proxy or root certificate).
+#[derive(Default)]
+pub struct TardisHttpClientBuilder {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    retry: RetryConfig,
+    cache: Option<Arc<dyn Cache>>,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+}
+
+impl TardisHttpClientBuilder {
+    /// Creates a new [`TardisHttpClientBuilder`] with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the Tardis API key (overrides the `TARDIS_API_KEY` environment variable).
+    #[must_use]
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the base URL of the Tardis HTTP API (overrides [`TARDIS_BASE_URL`]).
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the retry configuration for transient request failures.
+    #[must_use]
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Enables response caching, keyed by request URL, using `cache`.
+    #[must_use]
+    pub fn cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets the timeout for the entire request, from sending to reading the response body.
+    #[must_use]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing the TCP/TLS connection.
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through the given proxy (e.g. a corporate HTTP or SOCKS proxy).
+    #[must_use]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Adds an additional trusted TLS root certificate, e.g. for TLS-intercepting proxies.
+    #[must_use]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Builds the [`TardisHttpClient`], returning an [`Error::Build`] if the underlying
+    /// `reqwest::Client` could not be constructed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no API key was set and the `TARDIS_API_KEY` environment variable is not set.
+    pub fn build(self) -> Result<TardisHttpClient> {
+        let api_key = self.api_key.unwrap_or_else(|| {
             env::var("TARDIS_API_KEY").expect(
                 "API key must be provided or set in the 'TARDIS_API_KEY' environment variable",
             )
         });
 
-        Self {
-            base_url: base_url.unwrap_or(TARDIS_BASE_URL).to_string(),
+        let mut client_builder = reqwest::Client::builder().user_agent(USER_AGENT.clone());
+
+        if let Some(timeout) = self.request_timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            client_builder = client_builder.proxy(proxy);
+        }
+        for certificate in self.root_certificates {
+            client_builder = client_builder.add_root_certificate(certificate);
+        }
+
+        let client = client_builder.build()?;
+
+        Ok(TardisHttpClient {
+            base_url: self.base_url.unwrap_or_else(|| TARDIS_BASE_URL.to_string()),
             api_key,
-            client: reqwest::Client::builder()
-                .user_agent(USER_AGENT.clone())
-                .build()
-                .unwrap(),
+            client,
+            retry: self.retry,
+            cache: self.cache,
+        })
+    }
+}
+
+impl TardisHttpClient {
+    /// Creates a new [`TardisHttpClient`] instance.
+    pub fn new(api_key: Option<&str>, base_url: Option<&str>) -> Self {
+        Self::builder_from(api_key, base_url)
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Creates a new [`TardisHttpClient`] instance with the given `retry` configuration.
+    pub fn with_retry(
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+        retry: RetryConfig,
+    ) -> Self {
+        Self::builder_from(api_key, base_url)
+            .retry(retry)
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Creates a new [`TardisHttpClient`] instance that caches responses in `cache`, revalidating
+    /// stale entries with conditional requests rather than refetching unconditionally.
+    pub fn with_cache(
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+        cache: Arc<dyn Cache>,
+    ) -> Self {
+        Self::builder_from(api_key, base_url)
+            .cache(cache)
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Returns a [`TardisHttpClientBuilder`] for configuring timeouts, a proxy, or custom TLS
+    /// root certificates before constructing the client.
+    pub fn builder() -> TardisHttpClientBuilder {
+        TardisHttpClientBuilder::new()
+    }
+
+    fn builder_from(api_key: Option<&str>, base_url: Option<&str>) -> TardisHttpClientBuilder {
+        let mut builder = TardisHttpClientBuilder::new();
+        if let Some(api_key) = api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(base_url) = base_url {
+            builder = builder.base_url(base_url);
+        }
+        builder
+    }
+
+    /// Removes the cached [`Self::instruments`] response for `exchange`, if this client has a
+    /// cache. Cached single-instrument responses from [`Self::instrument`] are not removed.
+    pub fn invalidate_exchange_cache(&self, exchange: Exchange) {
+        if let Some(cache) = &self.cache {
+            let url = format!("{}/instruments/{exchange}", &self.base_url);
+            cache.remove(&url);
+        }
+    }
+
+    /// Removes all entries from this client's cache, if it has one.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
         }
     }
 
     /// Returns all instrument definitions for the given `exchange`.
     /// See <https://docs.tardis.dev/api/instruments-metadata-api>
     pub async fn instruments(&self, exchange: Exchange) -> Result<Response<Vec<InstrumentInfo>>> {
-        Ok(self
-            .client
-            .get(format!("{}/instruments/{exchange}", &self.base_url))
-            .bearer_auth(&self.api_key)
-            .send()
-            .await?
-            .json::<Response<Vec<InstrumentInfo>>>()
-            .await?)
+        let url = format!("{}/instruments/{exchange}", &self.base_url);
+        self.send_with_retry(url).await
     }
 
     /// Returns the instrument definition for a given `exchange` and `symbol`.
@@ -88,16 +485,330 @@ impl TardisHttpClient {
         exchange: Exchange,
         symbol: String,
     ) -> Result<Response<InstrumentInfo>> {
-        Ok(self
-            .client
-            .get(format!(
-                "{}/instruments/{exchange}/{symbol}",
-                &self.base_url
-            ))
-            .bearer_auth(&self.api_key)
-            .send()
-            .await?
-            .json::<Response<InstrumentInfo>>()
-            .await?)
+        let url = format!("{}/instruments/{exchange}/{symbol}", &self.base_url);
+        self.send_with_retry(url).await
+    }
+
+    /// Returns instrument definitions for each of `exchanges`, issuing the per-exchange requests
+    /// concurrently (bounded by `concurrency`) rather than serializing them one at a time.
+    ///
+    /// A failure fetching one exchange does not abort the batch: its `Err` is collected into the
+    /// result map alongside the other exchanges' results, so callers get partial success rather
+    /// than losing everything to one bad venue. The bound interacts correctly with the
+    /// retry/rate-limit layer, since each concurrent request still goes through
+    /// [`Self::send_with_retry`].
+    pub async fn instruments_many(
+        &self,
+        exchanges: &[Exchange],
+        concurrency: usize,
+    ) -> HashMap<Exchange, Result<Response<Vec<InstrumentInfo>>>> {
+        stream::iter(exchanges.iter().copied())
+            .map(|exchange| async move { (exchange, self.instruments(exchange).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Sends a `GET` request to `url`, serving a fresh cached response (or revalidating a stale
+    /// one) when this client has a [`Cache`], retrying transient failures per [`RetryConfig`],
+    /// and deserializes a `T` from the JSON body. Attaches `url` to any error so failures name
+    /// the endpoint involved.
+    async fn send_with_retry<T: serde::de::DeserializeOwned>(&self, url: String) -> Result<T> {
+        let body = self.get_body(&url).await?;
+        serde_json::from_str(&body).map_err(|source| Error::Deserialization { url, source })
+    }
+
+    /// Returns the response body for `url`, either served from cache or fetched from the
+    /// network (with conditional revalidation and retry on transient failure).
+    async fn get_body(&self, url: &str) -> Result<String> {
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(url));
+        if let Some(entry) = &cached {
+            if entry.is_fresh() {
+                return Ok(entry.body.clone());
+            }
+        }
+
+        let mut attempt = 0;
+
+        loop {
+            let result = self.send_once(url, cached.as_ref()).await;
+
+            let err = match result {
+                Ok(SendOutcome::NotModified) => {
+                    // `send_once` only returns `NotModified` when it attached a validator taken
+                    // from `cached`, so a cached entry is guaranteed to exist here.
+                    let refreshed = cached
+                        .as_ref()
+                        .expect("NotModified is only returned when a validator was sent")
+                        .refreshed();
+                    if let Some(cache) = &self.cache {
+                        cache.put(url, refreshed.clone());
+                    }
+                    return Ok(refreshed.body);
+                }
+                Ok(SendOutcome::Fresh(entry)) => {
+                    if let Some(cache) = &self.cache {
+                        if !entry.policy.no_store {
+                            cache.put(url, entry.clone());
+                        }
+                    }
+                    return Ok(entry.body);
+                }
+                Err(err) => err,
+            };
+
+            if attempt >= self.retry.max_retries || !err.is_retriable() {
+                return Err(if attempt > 0 {
+                    Error::RetriesExhausted {
+                        attempts: attempt + 1,
+                        url: url.to_string(),
+                        source: Box::new(err),
+                    }
+                } else {
+                    err
+                });
+            }
+
+            let retry_after = match &err {
+                Error::Api { retry_after, .. } => *retry_after,
+                _ => None,
+            };
+
+            let delay = self.retry.backoff_for(attempt, retry_after);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a single `GET` request to `url` with the client's bearer auth, attaching conditional
+    /// request headers when `cached` has a validator. Returns the freshly fetched entry, or
+    /// [`SendOutcome::NotModified`] on a `304` response to a request that attached a validator,
+    /// or an [`Error`] (with the `Retry-After` delay recorded via [`Error::Api`]) on an error
+    /// status — including an unexpected `304` to a request that sent no validator, since there is
+    /// no cached entry it could apply to.
+    async fn send_once(&self, url: &str, cached: Option<&CacheEntry>) -> Result<SendOutcome> {
+        let mut req = self.client.get(url).bearer_auth(&self.api_key);
+
+        let mut sent_validator = false;
+        if let Some(entry) = cached.filter(|entry| entry.has_validator()) {
+            if let Some(etag) = &entry.etag {
+                req = req.header(reqwest::header::IF_NONE_MATCH, etag);
+                sent_validator = true;
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                req = req.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+                sent_validator = true;
+            }
+        }
+
+        let resp = req.send().await.map_err(|source| Error::Request {
+            url: url.to_string(),
+            source,
+        })?;
+
+        if resp.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if sent_validator {
+                return Ok(SendOutcome::NotModified);
+            }
+
+            // We never attached a conditional header, so a 304 here is a spurious or
+            // misconfigured response (from the API, a CDN, or an intermediate proxy) rather than
+            // a valid revalidation result — there is no cached entry it could apply to.
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(Error::Api {
+                status,
+                url: url.to_string(),
+                body,
+                retry_after: None,
+            });
+        }
+
+        match resp.error_for_status_ref() {
+            Ok(_) => {
+                let etag = header_str(&resp, reqwest::header::ETAG);
+                let last_modified = header_str(&resp, reqwest::header::LAST_MODIFIED);
+                let policy = resp
+                    .headers()
+                    .get(reqwest::header::CACHE_CONTROL)
+                    .and_then(|v| v.to_str().ok())
+                    .map(CacheControlPolicy::parse)
+                    .unwrap_or_default();
+                let body = resp
+                    .text()
+                    .await
+                    .map_err(|source| Error::Request {
+                        url: url.to_string(),
+                        source,
+                    })?;
+
+                Ok(SendOutcome::Fresh(CacheEntry {
+                    body,
+                    etag,
+                    last_modified,
+                    policy,
+                    stored_at: Instant::now(),
+                }))
+            }
+            Err(_) => {
+                let status = resp.status();
+                let retry_after = resp
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(parse_retry_after);
+                let body = resp.text().await.unwrap_or_default();
+                Err(Error::Api {
+                    status,
+                    url: url.to_string(),
+                    body,
+                    retry_after,
+                })
+            }
+        }
+    }
+}
+
+/// The outcome of a single conditional `GET` attempt.
+enum SendOutcome {
+    /// The server returned `304 Not Modified`; the caller should serve the cached body.
+    NotModified,
+    /// The server returned a fresh `200` response.
+    Fresh(CacheEntry),
+}
+
+/// Returns the value of `name` on `resp` as an owned `String`, if present and valid UTF-8.
+fn header_str(resp: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+    resp.headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(jitter: bool) -> RetryConfig {
+        RetryConfig {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter,
+        }
+    }
+
+    #[test]
+    fn backoff_for_without_jitter_grows_exponentially_and_clamps_to_max() {
+        let retry = config(false);
+
+        assert_eq!(retry.backoff_for(0, None), Duration::from_millis(500));
+        assert_eq!(retry.backoff_for(1, None), Duration::from_millis(1000));
+        assert_eq!(retry.backoff_for(2, None), Duration::from_millis(2000));
+        assert_eq!(retry.backoff_for(10, None), retry.max_backoff);
+    }
+
+    #[test]
+    fn backoff_for_with_jitter_never_exceeds_the_unjittered_delay() {
+        let retry = config(true);
+        let unjittered = config(false);
+
+        for attempt in 0..8 {
+            let delay = retry.backoff_for(attempt, None);
+            assert!(delay <= unjittered.backoff_for(attempt, None));
+        }
+    }
+
+    #[test]
+    fn backoff_for_honors_retry_after_in_full_even_past_max_backoff() {
+        let retry = config(false);
+        let retry_after = Duration::from_secs(60);
+
+        assert_eq!(retry.backoff_for(0, Some(retry_after)), retry_after);
+    }
+
+    #[test]
+    fn backoff_for_with_jitter_never_shrinks_a_retry_after_delay() {
+        let retry = config(true);
+        let retry_after = Duration::from_secs(60);
+
+        for attempt in 0..8 {
+            let delay = retry.backoff_for(attempt, Some(retry_after));
+            assert!(delay >= retry_after);
+            assert!(delay <= retry_after + RETRY_AFTER_JITTER);
+        }
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_seconds() {
+        let value = reqwest::header::HeaderValue::from_static("120");
+        assert_eq!(parse_retry_after(&value), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_garbage() {
+        let value = reqwest::header::HeaderValue::from_static("not-a-date-or-number");
+        assert_eq!(parse_retry_after(&value), None);
+    }
+
+    #[test]
+    fn cache_control_policy_parses_max_age_and_no_store() {
+        let policy = CacheControlPolicy::parse("max-age=120, no-store");
+        assert_eq!(policy.max_age, Some(Duration::from_secs(120)));
+        assert!(policy.no_store);
+    }
+
+    #[test]
+    fn cache_control_policy_ignores_unknown_directives() {
+        let policy = CacheControlPolicy::parse("private, must-revalidate");
+        assert_eq!(policy.max_age, None);
+        assert!(!policy.no_store);
+    }
+
+    #[test]
+    fn cache_control_policy_parses_max_age_regardless_of_case() {
+        let policy = CacheControlPolicy::parse("MAX-AGE=60");
+        assert_eq!(policy.max_age, Some(Duration::from_secs(60)));
+
+        let policy = CacheControlPolicy::parse("Max-age=60");
+        assert_eq!(policy.max_age, Some(Duration::from_secs(60)));
+    }
+
+    fn entry_with_policy(policy: CacheControlPolicy) -> CacheEntry {
+        CacheEntry {
+            body: "body".to_string(),
+            etag: None,
+            last_modified: None,
+            policy,
+            stored_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn cache_entry_is_fresh_within_max_age_and_stale_after() {
+        let fresh = entry_with_policy(CacheControlPolicy {
+            max_age: Some(Duration::from_secs(60)),
+            no_store: false,
+        });
+        assert!(fresh.is_fresh());
+
+        let stale = entry_with_policy(CacheControlPolicy {
+            max_age: Some(Duration::from_secs(0)),
+            no_store: false,
+        });
+        assert!(!stale.is_fresh());
+
+        let no_policy = entry_with_policy(CacheControlPolicy::default());
+        assert!(!no_policy.is_fresh());
+    }
+
+    #[test]
+    fn cache_entry_refreshed_resets_the_freshness_window() {
+        let entry = entry_with_policy(CacheControlPolicy {
+            max_age: Some(Duration::from_secs(60)),
+            no_store: false,
+        });
+        let refreshed = entry.refreshed();
+        assert!(refreshed.is_fresh());
+        assert!(refreshed.stored_at >= entry.stored_at);
     }
 }